@@ -12,8 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Bridges Lance's [`CommitLock`]/[`CommitLease`]/[`ExternalManifestStore`]
+//! traits to Python implementations.
+//!
+//! The `#[tokio::test]`/`#[test]`s below call `Python::attach` directly,
+//! which needs an embedded interpreter rather than the one a Python process
+//! provides when loading this crate as an extension module. `extension-module`
+//! is an opt-in (non-default) feature in `Cargo.toml` for exactly this
+//! reason: a bare `cargo test` never activates it, so `auto-initialize`
+//! (pulled in through `[dev-dependencies]`) is free to start the
+//! interpreter these tests need.
+
 use std::fmt::Debug;
+#[cfg(not(feature = "sub-interpreter"))]
 use std::sync::LazyLock;
+#[cfg(feature = "sub-interpreter")]
+use std::{collections::HashMap, sync::Mutex};
 
 use lance_table::io::commit::external_manifest::ExternalManifestStore;
 use lance_table::io::commit::{CommitError, CommitLease, CommitLock};
@@ -23,54 +37,342 @@ use lance_core::Error;
 
 use pyo3::{exceptions::PyIOError, prelude::*};
 
-static PY_CONFLICT_ERROR: LazyLock<PyResult<Py<PyAny>>> = LazyLock::new(|| {
-    Python::attach(|py| {
-        py.import("lance")
-            .and_then(|lance| lance.getattr("commit"))
-            .and_then(|commit| commit.getattr("CommitConflictError"))
-            .map(|err| err.unbind())
-    })
-});
-
-fn handle_error(py_err: PyErr, py: Python) -> CommitError {
-    let conflict_err_type = match &*PY_CONFLICT_ERROR {
-        Ok(err) => err.bind(py).get_type(),
-        Err(import_error) => {
-            return CommitError::OtherError(Error::Internal {
-                message: format!("Error importing from pylance {}", import_error),
-                location: location!(),
-            })
+/// Interpreter-local id used to key caches of resolved Python objects.
+///
+/// `Py<PyAny>` handles are only valid within the interpreter that created
+/// them: under the `sub-interpreter` feature, embedders may run Lance inside
+/// more than one CPython sub-interpreter in the same process, each with its
+/// own copy of the `lance` module and distinct type identities. Caching a
+/// handle globally (as a plain `LazyLock`) would leak a type object from one
+/// interpreter into another and cause undefined behavior, so lookups are
+/// keyed on this id instead.
+#[cfg(feature = "sub-interpreter")]
+fn current_interpreter_id(_py: Python) -> isize {
+    // `_py` isn't read, but requiring it proves the GIL is held for the
+    // duration of these FFI calls.
+    unsafe { pyo3::ffi::PyInterpreterState_GetID(pyo3::ffi::PyInterpreterState_Get()) as isize }
+}
+
+/// Panics if `py` is not running in the sub-interpreter whose id is
+/// `created_on`, naming the offending handle as `what` in the message.
+///
+/// `Py<PyAny>` handles embed references that are only meaningful within the
+/// interpreter that produced them, so using one from a different
+/// sub-interpreter is undefined behavior rather than a recoverable error.
+#[cfg(feature = "sub-interpreter")]
+fn assert_same_interpreter(py: Python, created_on: isize, what: &str) {
+    let current = current_interpreter_id(py);
+    assert_eq!(
+        current, created_on,
+        "{what} was created in a different Python sub-interpreter and cannot be used here"
+    );
+}
+
+/// Returns `false` if `py` is not running in the sub-interpreter whose id is
+/// `created_on`, without panicking.
+///
+/// Used where calling into the handle on a mismatch would be unsound but
+/// isn't an option worth crashing the process over, e.g. a `Debug` impl that
+/// would rather print a placeholder than panic mid-format.
+#[cfg(feature = "sub-interpreter")]
+fn is_same_interpreter(py: Python, created_on: isize) -> bool {
+    current_interpreter_id(py) == created_on
+}
+
+fn resolve_conflict_error_type(py: Python) -> PyResult<Py<PyAny>> {
+    py.import("lance")
+        .and_then(|lance| lance.getattr("commit"))
+        .and_then(|commit| commit.getattr("CommitConflictError"))
+        .map(|err| err.unbind())
+}
+
+#[cfg(not(feature = "sub-interpreter"))]
+static PY_CONFLICT_ERROR: LazyLock<PyResult<Py<PyAny>>> =
+    LazyLock::new(|| Python::attach(resolve_conflict_error_type));
+
+#[cfg(feature = "sub-interpreter")]
+static PY_CONFLICT_ERROR_CACHE: Mutex<Option<HashMap<isize, Py<PyAny>>>> = Mutex::new(None);
+
+#[cfg(not(feature = "sub-interpreter"))]
+fn conflict_error_type(py: Python) -> PyResult<Py<PyAny>> {
+    match &*PY_CONFLICT_ERROR {
+        Ok(err) => Ok(err.clone_ref(py)),
+        Err(import_error) => Err(import_error.clone_ref(py)),
+    }
+}
+
+#[cfg(feature = "sub-interpreter")]
+fn conflict_error_type(py: Python) -> PyResult<Py<PyAny>> {
+    let id = current_interpreter_id(py);
+    if let Some(cached) = PY_CONFLICT_ERROR_CACHE
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .get(&id)
+    {
+        return Ok(cached.clone_ref(py));
+    }
+
+    let resolved = resolve_conflict_error_type(py)?;
+    PY_CONFLICT_ERROR_CACHE
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(id, resolved.clone_ref(py));
+    Ok(resolved)
+}
+
+fn resolve_retryable_error_type(py: Python) -> PyResult<Py<PyAny>> {
+    py.import("lance")
+        .and_then(|lance| lance.getattr("commit"))
+        .and_then(|commit| commit.getattr("RetryableCommitError"))
+        .map(|err| err.unbind())
+}
+
+#[cfg(not(feature = "sub-interpreter"))]
+static PY_RETRYABLE_ERROR: LazyLock<PyResult<Py<PyAny>>> =
+    LazyLock::new(|| Python::attach(resolve_retryable_error_type));
+
+#[cfg(feature = "sub-interpreter")]
+static PY_RETRYABLE_ERROR_CACHE: Mutex<Option<HashMap<isize, Py<PyAny>>>> = Mutex::new(None);
+
+#[cfg(not(feature = "sub-interpreter"))]
+fn retryable_error_type(py: Python) -> PyResult<Py<PyAny>> {
+    match &*PY_RETRYABLE_ERROR {
+        Ok(err) => Ok(err.clone_ref(py)),
+        Err(import_error) => Err(import_error.clone_ref(py)),
+    }
+}
+
+#[cfg(feature = "sub-interpreter")]
+fn retryable_error_type(py: Python) -> PyResult<Py<PyAny>> {
+    let id = current_interpreter_id(py);
+    if let Some(cached) = PY_RETRYABLE_ERROR_CACHE
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .get(&id)
+    {
+        return Ok(cached.clone_ref(py));
+    }
+
+    let resolved = resolve_retryable_error_type(py)?;
+    PY_RETRYABLE_ERROR_CACHE
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(id, resolved.clone_ref(py));
+    Ok(resolved)
+}
+
+/// How a Python exception raised by a commit-lock or external-manifest-store
+/// handler should be treated by the commit loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorClassification {
+    /// Another writer already holds this version; restart the commit from
+    /// the top (re-read the manifest, pick a new version).
+    Conflict,
+    /// A transient condition (throttling, a dropped connection, ...); the
+    /// same commit attempt is safe to retry with backoff.
+    Retryable,
+    /// Anything else; the commit attempt should be aborted.
+    Fatal,
+}
+
+/// Classifies `py_err`, preferring `classifier` (the optional callable
+/// passed to [`PyCommitLock::new`] / [`PyExternalManifestStore::new`]) over
+/// the hardcoded `lance.commit.CommitConflictError` /
+/// `lance.commit.RetryableCommitError` checks. `classifier` is expected to
+/// return one of the strings `"conflict"`, `"retryable"`, or `"fatal"`; any
+/// other return value (including the classifier raising) falls back to the
+/// hardcoded rules.
+fn classify_error(
+    py_err: &PyErr,
+    py: Python,
+    classifier: Option<&Py<PyAny>>,
+) -> ErrorClassification {
+    if let Some(classifier) = classifier {
+        let verdict = classifier
+            .call1(py, (py_err.clone_ref(py),))
+            .ok()
+            .and_then(|result| result.extract::<String>(py).ok());
+        match verdict.as_deref() {
+            Some("conflict") => return ErrorClassification::Conflict,
+            Some("retryable") => return ErrorClassification::Retryable,
+            Some("fatal") => return ErrorClassification::Fatal,
+            _ => {}
         }
-    };
+    }
 
-    if py_err.is_instance(py, &conflict_err_type) {
-        CommitError::CommitConflict
-    } else {
-        CommitError::OtherError(Error::Internal {
-            message: format!("Error from commit handler: {}", py_err),
+    if let Ok(conflict_type) = conflict_error_type(py) {
+        if py_err.is_instance(py, &conflict_type.bind(py).get_type()) {
+            return ErrorClassification::Conflict;
+        }
+    }
+    if let Ok(retryable_type) = retryable_error_type(py) {
+        if py_err.is_instance(py, &retryable_type.bind(py).get_type()) {
+            return ErrorClassification::Retryable;
+        }
+    }
+    ErrorClassification::Fatal
+}
+
+/// Maps `py_err` to a [`CommitError`] via [`classify_error`]. `context`
+/// names the operation that raised it (e.g. `"commit handler"` or
+/// `"external store get_latest_version"`) so the resulting message stays
+/// diagnosable instead of collapsing every handler into the same generic
+/// text.
+fn handle_error(
+    py_err: PyErr,
+    py: Python,
+    classifier: Option<&Py<PyAny>>,
+    context: &str,
+    attempts_made: u32,
+) -> CommitError {
+    match classify_error(&py_err, py, classifier) {
+        ErrorClassification::Conflict => CommitError::CommitConflict,
+        ErrorClassification::Retryable => CommitError::OtherError(Error::Internal {
+            message: format!(
+                "Retryable error from {}, giving up after {} attempts: {}",
+                context, attempts_made, py_err
+            ),
             location: location!(),
-        })
+        }),
+        ErrorClassification::Fatal => CommitError::OtherError(Error::Internal {
+            message: format!("Error from {}: {}", context, py_err),
+            location: location!(),
+        }),
+    }
+}
+
+/// How many times, and with how much delay, an `ErrorClassification::Retryable`
+/// error is retried before being given up on and surfaced to the caller.
+///
+/// `lance_table::io::commit::CommitError` has no retryable variant for an
+/// outer commit loop to act on, so this bridge backs off and retries the
+/// Python call itself instead of propagating the distinction upward. These
+/// knobs let callers tune (or disable, via `max_attempts: 1`) that behavior
+/// for their workload instead of inheriting one hardcoded policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts made, including the first, before giving up.
+    pub max_attempts: u32,
+    /// Base delay for the exponential backoff between attempts.
+    pub base_delay: std::time::Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: std::time::Duration::from_millis(50),
+        }
+    }
+}
+
+/// Sleeps for the backoff delay of the given (zero-indexed) attempt.
+async fn retry_backoff(attempt: u32, policy: &RetryPolicy) {
+    let delay = policy.base_delay * 2u32.pow(attempt.min(6));
+    tokio::time::sleep(delay).await;
+}
+
+/// Whether `py_err` should be retried given it was raised by the
+/// `attempt`-th (zero-indexed) attempt, i.e. whether it classifies as
+/// [`ErrorClassification::Retryable`] and attempts remain under `policy`.
+fn should_retry(
+    py_err: &PyErr,
+    py: Python,
+    classifier: Option<&Py<PyAny>>,
+    attempt: u32,
+    policy: &RetryPolicy,
+) -> bool {
+    classify_error(py_err, py, classifier) == ErrorClassification::Retryable
+        && attempt + 1 < policy.max_attempts
+}
+
+/// If `obj` is a coroutine (i.e. the result of calling an `async def`
+/// method), drives it to completion on the Tokio runtime via
+/// `pyo3-async-runtimes` and returns its result. Any other value is returned
+/// unchanged, including awaitables that are not coroutines (only
+/// `asyncio.iscoroutine` is checked), so synchronous Python implementations
+/// keep working without going through the Tokio bridge at all.
+async fn resolve_maybe_async(obj: Py<PyAny>) -> PyResult<Py<PyAny>> {
+    let is_coroutine = Python::attach(|py| -> PyResult<bool> {
+        py.import("asyncio")?
+            .call_method1("iscoroutine", (&obj,))?
+            .extract()
+    })?;
+
+    if !is_coroutine {
+        return Ok(obj);
     }
+
+    let future =
+        Python::attach(|py| pyo3_async_runtimes::tokio::into_future(obj.bind(py).clone()))?;
+    future.await
+}
+
+/// Enters `cm`, preferring the async protocol (`__aenter__`) when `cm`
+/// supports it and falling back to the sync protocol (`__enter__`)
+/// otherwise, so both `async def __aenter__` and plain `def __enter__`
+/// context managers work through the same call site.
+async fn call_enter(cm: &Py<PyAny>) -> PyResult<()> {
+    let has_aenter = Python::attach(|py| cm.bind(py).hasattr("__aenter__"))?;
+    let result = if has_aenter {
+        Python::attach(|py| cm.call_method0(py, "__aenter__"))?
+    } else {
+        Python::attach(|py| cm.call_method0(py, "__enter__"))?
+    };
+    resolve_maybe_async(result).await?;
+    Ok(())
+}
+
+/// Exits `cm` with the given `(exc_type, exc_value, traceback)` triple,
+/// preferring `__aexit__` over `__exit__` the same way [`call_enter`] does.
+async fn call_exit(cm: &Py<PyAny>, args: (Py<PyAny>, Py<PyAny>, Py<PyAny>)) -> PyResult<()> {
+    let has_aexit = Python::attach(|py| cm.bind(py).hasattr("__aexit__"))?;
+    let result = if has_aexit {
+        Python::attach(|py| cm.call_method1(py, "__aexit__", args))?
+    } else {
+        Python::attach(|py| cm.call_method1(py, "__exit__", args))?
+    };
+    resolve_maybe_async(result).await?;
+    Ok(())
 }
 
 pub struct PyCommitLock {
     inner: Py<PyAny>,
+    /// Optional callable `(exception) -> "conflict" | "retryable" | "fatal"`
+    /// that overrides the hardcoded exception-type classification in
+    /// [`handle_error`].
+    classifier: Option<Py<PyAny>>,
+    #[cfg(feature = "sub-interpreter")]
+    created_on: isize,
 }
 
 impl PyCommitLock {
-    pub fn new(inner: Py<PyAny>) -> Self {
-        Self { inner }
+    pub fn new(inner: Py<PyAny>, classifier: Option<Py<PyAny>>) -> Self {
+        Self {
+            inner,
+            classifier,
+            #[cfg(feature = "sub-interpreter")]
+            created_on: Python::attach(current_interpreter_id),
+        }
     }
 }
 
 impl Debug for PyCommitLock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let repr = Python::attach(|py| {
+            #[cfg(feature = "sub-interpreter")]
+            if !is_same_interpreter(py, self.created_on) {
+                return None;
+            }
             self.inner
-                .call_method0(py, "__repr__")?
-                .extract::<String>(py)
-        })
-        .ok();
+                .call_method0(py, "__repr__")
+                .and_then(|r| r.extract::<String>(py))
+                .ok()
+        });
         f.debug_struct("PyCommitLock")
             .field("inner", &repr)
             .finish()
@@ -81,33 +383,89 @@ impl Debug for PyCommitLock {
 impl CommitLock for PyCommitLock {
     type Lease = PyCommitLease;
 
+    /// Makes a single, non-retried attempt at acquiring the lock.
+    ///
+    /// Like `put_if_not_exists`/`put_if_exists` (see [`store_call_once`]),
+    /// acquiring a lock is a non-idempotent, side-effecting operation: if the
+    /// Python context manager's `__enter__`/`__aenter__` raises a
+    /// `Retryable` error after the acquire actually landed server-side,
+    /// blindly retrying would call `self.inner(version)` again and enter a
+    /// *second* context manager, typically self-conflicting against the
+    /// lock this attempt already holds rather than recovering from it.
+    /// Surfacing the error once and letting the commit loop restart from a
+    /// fresh version is safer than retrying the acquire in place.
     async fn lock(&self, version: u64) -> Result<Self::Lease, CommitError> {
-        let lease = Python::attach(|py| -> Result<_, CommitError> {
-            let lease = self
-                .inner
-                .call1(py, (version,))
-                .map_err(|err| handle_error(err, py))?;
-            lease
-                .call_method0(py, "__enter__")
-                .map_err(|err| handle_error(err, py))?;
-            Ok(lease)
+        self.try_lock_once(version).await.map_err(|py_err| {
+            Python::attach(|py| {
+                handle_error(py_err, py, self.classifier.as_ref(), "commit handler", 1)
+            })
+        })
+    }
+}
+
+impl PyCommitLock {
+    /// Makes a single attempt at acquiring the lock, without retrying.
+    async fn try_lock_once(&self, version: u64) -> PyResult<PyCommitLease> {
+        let call_result = Python::attach(|py| -> PyResult<_> {
+            #[cfg(feature = "sub-interpreter")]
+            assert_same_interpreter(py, self.created_on, "PyCommitLock");
+            self.inner.call1(py, (version,))
         })?;
-        Ok(PyCommitLease { inner: lease })
+        // `self.inner(version)` may itself be an `async def`, in which case
+        // it returns a coroutine that yields the actual context manager.
+        let lease = resolve_maybe_async(call_result).await?;
+        call_enter(&lease).await?;
+
+        Ok(PyCommitLease {
+            inner: lease,
+            classifier: self
+                .classifier
+                .as_ref()
+                .map(|c| Python::attach(|py| c.clone_ref(py))),
+            #[cfg(feature = "sub-interpreter")]
+            created_on: self.created_on,
+        })
     }
 }
 
 pub struct PyCommitLease {
     inner: Py<PyAny>,
+    classifier: Option<Py<PyAny>>,
+    #[cfg(feature = "sub-interpreter")]
+    created_on: isize,
 }
 
 #[async_trait::async_trait]
 impl CommitLease for PyCommitLease {
+    /// Makes a single, non-retried attempt at exiting the lease's context
+    /// manager.
+    ///
+    /// Releasing a lock is as non-idempotent as acquiring one (see
+    /// [`PyCommitLock::lock`]): if `__exit__`/`__aexit__` raises a
+    /// `Retryable` error after the release actually landed server-side,
+    /// calling it again typically self-conflicts against a lock this
+    /// attempt no longer holds. Surfacing the error once, rather than
+    /// retrying the release in place, leaves it to the caller (and the
+    /// lock's own timeout) to recover.
     async fn release(&self, success: bool) -> Result<(), CommitError> {
-        Python::attach(|py| {
+        #[cfg(feature = "sub-interpreter")]
+        Python::attach(|py| assert_same_interpreter(py, self.created_on, "PyCommitLease"));
+
+        self.try_release_once(success).await.map_err(|py_err| {
+            Python::attach(|py| {
+                handle_error(py_err, py, self.classifier.as_ref(), "commit handler", 1)
+            })
+        })
+    }
+}
+
+impl PyCommitLease {
+    /// Makes a single attempt at exiting the lease's context manager, without
+    /// retrying.
+    async fn try_release_once(&self, success: bool) -> PyResult<()> {
+        let exc_args = Python::attach(|py| -> (Py<PyAny>, Py<PyAny>, Py<PyAny>) {
             if success {
-                self.inner
-                    .call_method1(py, "__exit__", (py.None(), py.None(), py.None()))
-                    .map_err(|err| handle_error(err, py))
+                (py.None(), py.None(), py.None())
             } else {
                 // If the commit failed, we pass up an exception to the
                 // context manager.
@@ -119,20 +477,15 @@ impl CommitLease for PyCommitLease {
                     .unwrap()
                     .call0()
                     .unwrap();
-                self.inner
-                    .call_method1(
-                        py,
-                        "__exit__",
-                        (
-                            args.get_item(0).unwrap(),
-                            args.get_item(1).unwrap(),
-                            args.get_item(2).unwrap(),
-                        ),
-                    )
-                    .map_err(|err| handle_error(err, py))
+                (
+                    args.get_item(0).unwrap().unbind(),
+                    args.get_item(1).unwrap().unbind(),
+                    args.get_item(2).unwrap().unbind(),
+                )
             }
-        })?;
-        Ok(())
+        });
+
+        call_exit(&self.inner, exc_args).await
     }
 }
 
@@ -141,48 +494,189 @@ impl CommitLease for PyCommitLease {
 /// Python and Rust APIs, ensuring atomic commits across both languages.
 pub struct PyExternalManifestStore {
     inner: Py<PyAny>,
+    /// Optional callable `(exception) -> "conflict" | "retryable" | "fatal"`
+    /// that overrides the hardcoded exception-type classification in
+    /// [`handle_error`].
+    classifier: Option<Py<PyAny>>,
+    retry_policy: RetryPolicy,
+    #[cfg(feature = "sub-interpreter")]
+    created_on: isize,
 }
 
 impl PyExternalManifestStore {
-    pub fn new(inner: Py<PyAny>) -> Self {
-        Self { inner }
+    pub fn new(inner: Py<PyAny>, classifier: Option<Py<PyAny>>) -> Self {
+        Self {
+            inner,
+            classifier,
+            retry_policy: RetryPolicy::default(),
+            #[cfg(feature = "sub-interpreter")]
+            created_on: Python::attach(current_interpreter_id),
+        }
+    }
+
+    /// Overrides the default policy for retrying `ErrorClassification::Retryable`
+    /// errors raised by `get`/`get_latest_version`/`delete` (the mutating puts
+    /// are never retried; see [`store_call_once`]).
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 }
 
 impl std::fmt::Debug for PyExternalManifestStore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let repr = Python::attach(|py| {
+            #[cfg(feature = "sub-interpreter")]
+            if !is_same_interpreter(py, self.created_on) {
+                return None;
+            }
             self.inner
-                .call_method0(py, "__repr__")?
-                .extract::<String>(py)
-        })
-        .ok();
+                .call_method0(py, "__repr__")
+                .and_then(|r| r.extract::<String>(py))
+                .ok()
+        });
         f.debug_struct("PyExternalManifestStore")
             .field("inner", &repr)
             .finish()
     }
 }
 
+/// Maps the final (non-retried, or retries-exhausted) error raised by an
+/// `ExternalManifestStore` handler to a Rust [`Error`], routing it through
+/// the same classifier-aware [`handle_error`] the commit-lock path uses so a
+/// user-supplied classifier (or the hardcoded conflict/retryable checks)
+/// applies consistently across the whole manifest-store surface. `get`
+/// additionally maps a `KeyError` to [`Error::NotFound`] ahead of
+/// classification, since a missing key is an expected outcome of `get`, not
+/// something to classify.
+///
+/// Called from [`with_store_retries`] (used by `get`, `get_latest_version`
+/// and `delete`, which has already retried any
+/// `ErrorClassification::Retryable` error before this is reached) and from
+/// [`store_call_once`] (used by the puts, which are never retried), so the
+/// mapping is identical whether the handler is a plain function or an
+/// `async def`.
+fn store_error(
+    err: PyErr,
+    py: Python,
+    classifier: Option<&Py<PyAny>>,
+    operation: &str,
+    base_uri: &str,
+    version: u64,
+    is_get: bool,
+    attempts_made: u32,
+) -> Error {
+    if is_get && err.is_instance_of::<pyo3::exceptions::PyKeyError>(py) {
+        Error::NotFound {
+            uri: format!("{}@{}", base_uri, version),
+            location: location!(),
+        }
+    } else {
+        let context = format!("external store {}", operation);
+        Error::from(handle_error(err, py, classifier, &context, attempts_made))
+    }
+}
+
+/// Makes a single, non-retried call and maps any error through
+/// [`store_error`].
+///
+/// Used for `put_if_not_exists`/`put_if_exists`: unlike `get` or `delete`,
+/// these are not safe for this bridge to retry blindly. A "retryable" error
+/// (a dropped connection, a timed-out response, ...) can still mean the
+/// write landed server-side; re-issuing it risks a spurious conflict against
+/// our own prior attempt, or corrupting store state, since this bridge has
+/// no store-specific way to tell "already written by this attempt" from a
+/// genuine conflict. Surfacing the error once and letting the commit loop
+/// decide whether to restart the whole commit (picking a new version) is
+/// safer than retrying the write in place.
+async fn store_call_once<T, F, Fut>(
+    classifier: Option<&Py<PyAny>>,
+    operation: &str,
+    base_uri: &str,
+    version: u64,
+    is_get: bool,
+    call: F,
+) -> Result<T, Error>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = PyResult<T>>,
+{
+    call().await.map_err(|err| {
+        Python::attach(|py| {
+            store_error(err, py, classifier, operation, base_uri, version, is_get, 1)
+        })
+    })
+}
+
+/// Runs `attempt_once` to completion, retrying on backoff per `retry_policy`
+/// while it keeps raising a [`ErrorClassification::Retryable`] error, then
+/// maps the final outcome through [`store_error`]. Only safe for idempotent
+/// operations (`get`, `get_latest_version`, `delete`); see
+/// [`store_call_once`] for why the mutating puts don't use this.
+async fn with_store_retries<T, F, Fut>(
+    classifier: Option<&Py<PyAny>>,
+    retry_policy: &RetryPolicy,
+    operation: &str,
+    base_uri: &str,
+    version: u64,
+    is_get: bool,
+    mut attempt_once: F,
+) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = PyResult<T>>,
+{
+    let mut attempt = 0u32;
+    loop {
+        match attempt_once().await {
+            Ok(value) => return Ok(value),
+            Err(py_err) => {
+                if Python::attach(|py| {
+                    should_retry(&py_err, py, classifier, attempt, retry_policy)
+                }) {
+                    retry_backoff(attempt, retry_policy).await;
+                    attempt += 1;
+                    continue;
+                }
+                return Err(Python::attach(|py| {
+                    store_error(
+                        py_err,
+                        py,
+                        classifier,
+                        operation,
+                        base_uri,
+                        version,
+                        is_get,
+                        attempt + 1,
+                    )
+                }));
+            }
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl ExternalManifestStore for PyExternalManifestStore {
     async fn get(&self, base_uri: &str, version: u64) -> Result<String, Error> {
-        Python::attach(|py| -> Result<String, Error> {
-            let result = self
-                .inner
-                .call_method1(py, "get", (base_uri, version))
-                .map_err(|err| {
-                    if err.is_instance_of::<pyo3::exceptions::PyKeyError>(py) {
-                        Error::NotFound {
-                            uri: format!("{}@{}", base_uri, version),
-                            location: location!(),
-                        }
-                    } else {
-                        Error::Internal {
-                            message: format!("Error from external store get: {}", err),
-                            location: location!(),
-                        }
-                    }
+        let result = with_store_retries(
+            self.classifier.as_ref(),
+            &self.retry_policy,
+            "get",
+            base_uri,
+            version,
+            true,
+            || async {
+                let call_result = Python::attach(|py| -> PyResult<_> {
+                    #[cfg(feature = "sub-interpreter")]
+                    assert_same_interpreter(py, self.created_on, "PyExternalManifestStore");
+                    self.inner.call_method1(py, "get", (base_uri, version))
                 })?;
+                resolve_maybe_async(call_result).await
+            },
+        )
+        .await?;
+
+        Python::attach(|py| {
             result.extract::<String>(py).map_err(|err| Error::Internal {
                 message: format!("Failed to extract string from get result: {}", err),
                 location: location!(),
@@ -191,15 +685,26 @@ impl ExternalManifestStore for PyExternalManifestStore {
     }
 
     async fn get_latest_version(&self, base_uri: &str) -> Result<Option<(u64, String)>, Error> {
-        Python::attach(|py| -> Result<Option<(u64, String)>, Error> {
-            let result = self
-                .inner
-                .call_method1(py, "get_latest_version", (base_uri,))
-                .map_err(|err| Error::Internal {
-                    message: format!("Error from external store get_latest_version: {}", err),
-                    location: location!(),
+        let result = with_store_retries(
+            self.classifier.as_ref(),
+            &self.retry_policy,
+            "get_latest_version",
+            base_uri,
+            0,
+            false,
+            || async {
+                let call_result = Python::attach(|py| -> PyResult<_> {
+                    #[cfg(feature = "sub-interpreter")]
+                    assert_same_interpreter(py, self.created_on, "PyExternalManifestStore");
+                    self.inner
+                        .call_method1(py, "get_latest_version", (base_uri,))
                 })?;
+                resolve_maybe_async(call_result).await
+            },
+        )
+        .await?;
 
+        Python::attach(|py| -> Result<Option<(u64, String)>, Error> {
             if result.is_none(py) {
                 return Ok(None);
             }
@@ -224,22 +729,32 @@ impl ExternalManifestStore for PyExternalManifestStore {
         size: u64,
         e_tag: Option<String>,
     ) -> Result<(), Error> {
-        Python::attach(|py| -> Result<(), Error> {
-            let e_tag_py = match e_tag {
-                Some(ref tag) => tag.into_pyobject(py).unwrap().into_any().unbind(),
-                None => py.None(),
-            };
-
-            self.inner
-                .call_method1(
-                    py,
-                    "put_if_not_exists",
-                    (base_uri, version, path, size, e_tag_py),
-                )
-                .map_err(|err| Error::from(handle_error(err, py)))?;
+        store_call_once(
+            self.classifier.as_ref(),
+            "put_if_not_exists",
+            base_uri,
+            version,
+            false,
+            || async {
+                let call_result = Python::attach(|py| -> PyResult<_> {
+                    #[cfg(feature = "sub-interpreter")]
+                    assert_same_interpreter(py, self.created_on, "PyExternalManifestStore");
+                    let e_tag_py = match &e_tag {
+                        Some(tag) => tag.into_pyobject(py).unwrap().into_any().unbind(),
+                        None => py.None(),
+                    };
+                    self.inner.call_method1(
+                        py,
+                        "put_if_not_exists",
+                        (base_uri, version, path, size, e_tag_py),
+                    )
+                })?;
+                resolve_maybe_async(call_result).await
+            },
+        )
+        .await?;
 
-            Ok(())
-        })
+        Ok(())
     }
 
     async fn put_if_exists(
@@ -250,34 +765,268 @@ impl ExternalManifestStore for PyExternalManifestStore {
         size: u64,
         e_tag: Option<String>,
     ) -> Result<(), Error> {
-        Python::attach(|py| -> Result<(), Error> {
-            let e_tag_py = match e_tag {
-                Some(ref tag) => tag.into_pyobject(py).unwrap().into_any().unbind(),
-                None => py.None(),
-            };
-
-            self.inner
-                .call_method1(
-                    py,
-                    "put_if_exists",
-                    (base_uri, version, path, size, e_tag_py),
-                )
-                .map_err(|err| Error::from(handle_error(err, py)))?;
+        store_call_once(
+            self.classifier.as_ref(),
+            "put_if_exists",
+            base_uri,
+            version,
+            false,
+            || async {
+                let call_result = Python::attach(|py| -> PyResult<_> {
+                    #[cfg(feature = "sub-interpreter")]
+                    assert_same_interpreter(py, self.created_on, "PyExternalManifestStore");
+                    let e_tag_py = match &e_tag {
+                        Some(tag) => tag.into_pyobject(py).unwrap().into_any().unbind(),
+                        None => py.None(),
+                    };
+                    self.inner.call_method1(
+                        py,
+                        "put_if_exists",
+                        (base_uri, version, path, size, e_tag_py),
+                    )
+                })?;
+                resolve_maybe_async(call_result).await
+            },
+        )
+        .await?;
 
-            Ok(())
-        })
+        Ok(())
     }
 
     async fn delete(&self, base_uri: &str) -> Result<(), Error> {
-        Python::attach(|py| -> Result<(), Error> {
-            self.inner
-                .call_method1(py, "delete", (base_uri,))
-                .map_err(|err| Error::Internal {
-                    message: format!("Error from external store delete: {}", err),
-                    location: location!(),
+        with_store_retries(
+            self.classifier.as_ref(),
+            &self.retry_policy,
+            "delete",
+            base_uri,
+            0,
+            false,
+            || async {
+                let call_result = Python::attach(|py| -> PyResult<_> {
+                    #[cfg(feature = "sub-interpreter")]
+                    assert_same_interpreter(py, self.created_on, "PyExternalManifestStore");
+                    self.inner.call_method1(py, "delete", (base_uri,))
                 })?;
+                resolve_maybe_async(call_result).await
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use pyo3::{exceptions::PyValueError, types::PyDict};
 
-            Ok(())
+    use super::*;
+
+    #[tokio::test]
+    async fn resolve_maybe_async_returns_sync_values_unchanged() {
+        let value = Python::attach(|py| 42i32.into_pyobject(py).unwrap().into_any().unbind());
+
+        let resolved = resolve_maybe_async(value).await.unwrap();
+
+        Python::attach(|py| assert_eq!(resolved.extract::<i32>(py).unwrap(), 42));
+    }
+
+    #[tokio::test]
+    async fn resolve_maybe_async_awaits_coroutines() {
+        let coroutine = Python::attach(|py| -> PyResult<Py<PyAny>> {
+            let locals = PyDict::new(py);
+            py.run(c"async def make():\n    return 42\n", None, Some(&locals))?;
+            Ok(locals.get_item("make")?.unwrap().call0()?.unbind())
+        })
+        .unwrap();
+
+        let resolved = resolve_maybe_async(coroutine).await.unwrap();
+
+        Python::attach(|py| assert_eq!(resolved.extract::<i32>(py).unwrap(), 42));
+    }
+
+    #[test]
+    fn classify_error_prefers_the_classifier_over_hardcoded_types() {
+        Python::attach(|py| {
+            // A plain `ValueError` isn't `CommitConflictError`, so the
+            // hardcoded rules alone would classify this as `Fatal`.
+            let err = PyValueError::new_err("boom");
+            let classifier = py.eval(c"lambda exc: \"conflict\"", None, None).unwrap();
+
+            assert_eq!(
+                classify_error(&err, py, Some(&classifier.unbind())),
+                ErrorClassification::Conflict
+            );
+        });
+    }
+
+    #[cfg(feature = "sub-interpreter")]
+    #[test]
+    fn is_same_interpreter_matches_only_the_current_id() {
+        Python::attach(|py| {
+            let this_id = current_interpreter_id(py);
+            assert!(is_same_interpreter(py, this_id));
+            assert!(!is_same_interpreter(py, this_id.wrapping_add(1)));
+        });
+    }
+
+    #[cfg(feature = "sub-interpreter")]
+    #[test]
+    fn assert_same_interpreter_panics_on_mismatch() {
+        let panicked = std::panic::catch_unwind(|| {
+            Python::attach(|py| {
+                let other_id = current_interpreter_id(py).wrapping_add(1);
+                assert_same_interpreter(py, other_id, "test handle");
+            });
         })
+        .is_err();
+
+        assert!(panicked);
+    }
+
+    #[cfg(feature = "sub-interpreter")]
+    #[test]
+    fn conflict_error_cache_is_keyed_by_interpreter_id() {
+        // This test owns `PY_CONFLICT_ERROR_CACHE` for its duration: no
+        // other test in this module resolves `CommitConflictError`, so
+        // seeding the cache directly (rather than via a real `import lance`,
+        // which isn't available in this test binary) doesn't race.
+        Python::attach(|py| {
+            let this_id = current_interpreter_id(py);
+            let other_id = this_id.wrapping_add(1);
+            let sentinel_for_this = 123i32.into_pyobject(py).unwrap().into_any().unbind();
+            let sentinel_for_other = 456i32.into_pyobject(py).unwrap().into_any().unbind();
+
+            {
+                let mut cache = PY_CONFLICT_ERROR_CACHE.lock().unwrap();
+                let map = cache.get_or_insert_with(HashMap::new);
+                map.insert(this_id, sentinel_for_this);
+                map.insert(other_id, sentinel_for_other);
+            }
+
+            // A lookup under the current interpreter id must hit its own
+            // entry, not the one cached under a different interpreter id.
+            let resolved = conflict_error_type(py).unwrap();
+            assert_eq!(resolved.extract::<i32>(py).unwrap(), 123);
+        });
+    }
+
+    fn always_retryable_classifier() -> Py<PyAny> {
+        Python::attach(|py| {
+            py.eval(c"lambda exc: \"retryable\"", None, None)
+                .unwrap()
+                .unbind()
+        })
+    }
+
+    #[tokio::test]
+    async fn with_store_retries_retries_up_to_max_attempts_then_gives_up() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            base_delay: std::time::Duration::ZERO,
+        };
+        let classifier = always_retryable_classifier();
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), Error> = with_store_retries(
+            Some(&classifier),
+            &policy,
+            "get",
+            "mem://test",
+            0,
+            true,
+            || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err(PyValueError::new_err("boom")) }
+            },
+        )
+        .await;
+
+        // `max_attempts` counts the initial attempt, so exactly that many
+        // calls are made, never more.
+        assert_eq!(calls.load(Ordering::SeqCst), policy.max_attempts);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn with_store_retries_stops_as_soon_as_an_attempt_succeeds() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            base_delay: std::time::Duration::ZERO,
+        };
+        let classifier = always_retryable_classifier();
+        let calls = AtomicU32::new(0);
+
+        let result = with_store_retries(
+            Some(&classifier),
+            &policy,
+            "get",
+            "mem://test",
+            0,
+            true,
+            || {
+                let attempt = calls.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt < 2 {
+                        Err(PyValueError::new_err("boom"))
+                    } else {
+                        Ok(42i32)
+                    }
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn store_call_once_never_retries_even_a_retryable_error() {
+        // `put_if_not_exists`/`put_if_exists` use `store_call_once` instead
+        // of `with_store_retries` specifically because blindly retrying a
+        // non-idempotent write is unsafe; assert that holds even when the
+        // classifier says the error is retryable.
+        let classifier = always_retryable_classifier();
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), Error> = store_call_once(
+            Some(&classifier),
+            "put_if_not_exists",
+            "mem://test",
+            0,
+            false,
+            || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err(PyValueError::new_err("boom")) }
+            },
+        )
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn call_enter_and_call_exit_prefer_the_async_protocol() {
+        let cm = Python::attach(|py| -> PyResult<Py<PyAny>> {
+            let locals = PyDict::new(py);
+            py.run(
+                c"class AsyncOnly:\n    async def __aenter__(self):\n        return self\n    async def __aexit__(self, *args):\n        return False\ncm = AsyncOnly()\n",
+                None,
+                Some(&locals),
+            )?;
+            Ok(locals.get_item("cm")?.unwrap().unbind())
+        })
+        .unwrap();
+
+        // Neither call would succeed by falling back to `__enter__`/`__exit__`,
+        // since `AsyncOnly` only defines the async protocol.
+        call_enter(&cm).await.unwrap();
+
+        let exc_args = Python::attach(|py| (py.None(), py.None(), py.None()));
+        call_exit(&cm, exc_args).await.unwrap();
     }
 }